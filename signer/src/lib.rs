@@ -1,9 +1,19 @@
-use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+use k256::ecdsa::{signature::hazmat::PrehashSigner, RecoveryId, Signature, SigningKey};
 use k256::elliptic_curve::rand_core::OsRng;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use std::time::Instant;
 
+mod paillier;
+mod threshold;
+use threshold::{
+    aggregate, round1_commit, round2_partial_sign, split_key, KeyShare, NonceCommitment,
+    NonceShare, PartialSignature,
+};
+
+mod psbt;
+use psbt::PartialTx;
+
 /// MPC-Compatible Signature Result
 ///
 /// In a real MPC implementation, the signature would be computed
@@ -17,7 +27,9 @@ struct SignatureResult {
     r: Vec<u8>,
     /// s component (32 bytes)
     s: Vec<u8>,
-    /// Recovery ID (0 or 1, +27 for Ethereum)
+    /// Raw recovery id (0 or 1) as returned by k256
+    recovery_id: u8,
+    /// Ethereum-style recovery value (27 + recovery_id)
     v: u8,
     /// Signing latency in milliseconds
     latency_ms: f64,
@@ -25,11 +37,10 @@ struct SignatureResult {
 
 /// Production signing interface
 ///
-/// This uses the k256 crate for real ECDSA operations.
-/// In production with MPC, replace the key generation with
-/// threshold key share aggregation.
+/// This uses the k256 crate for real ECDSA operations. For a threshold/MPC
+/// flow where no single party holds this key, see the [`threshold`] module.
 struct ProductionSigner {
-    /// The signing key (in production, this would be MPC key shares)
+    /// The signing key (for threshold signing, see [`threshold::KeyShare`])
     key: SigningKey,
 }
 
@@ -58,7 +69,8 @@ impl ProductionSigner {
     ///     digest: 32-byte message hash to sign
     ///
     /// Returns:
-    ///     SignatureResult with signature components
+    ///     SignatureResult with signature components, including the real
+    ///     ECDSA recovery id so the signature can be used with `ecrecover`.
     fn sign(&self, digest: &[u8]) -> Result<SignatureResult, String> {
         let start = Instant::now();
 
@@ -66,8 +78,12 @@ impl ProductionSigner {
             return Err(format!("Digest must be 32 bytes, got {}", digest.len()));
         }
 
-        // Create the signature
-        let signature: Signature = self.key.sign(digest);
+        // Create the signature along with its recovery id so the signer's
+        // public key can be recovered from (digest, r, s, recid) alone.
+        let (signature, recid): (Signature, RecoveryId) = self
+            .key
+            .sign_prehash_recoverable(digest)
+            .map_err(|e| format!("Signing failed: {}", e))?;
         let sig_bytes = signature.to_bytes();
 
         // Extract r and s components
@@ -80,16 +96,34 @@ impl ProductionSigner {
             signature: sig_bytes.to_vec(),
             r,
             s,
-            v: 27, // Default recovery ID for Ethereum
+            recovery_id: recid.to_byte(),
+            v: 27 + recid.to_byte(),
             latency_ms,
         })
     }
 
     /// Get the public key (for address derivation)
-    fn public_key(&self) -> Vec<u8> {
+    ///
+    /// Args:
+    ///     compressed: If true, return the 33-byte SEC1 compressed encoding;
+    ///         otherwise return the 65-byte uncompressed encoding.
+    fn public_key(&self, compressed: bool) -> Vec<u8> {
         use k256::ecdsa::VerifyingKey;
         let verifying_key: VerifyingKey = self.key.verifying_key().clone();
-        verifying_key.to_encoded_point(false).as_bytes().to_vec()
+        verifying_key.to_encoded_point(compressed).as_bytes().to_vec()
+    }
+}
+
+/// Computes the recovery value for the `v` field.
+///
+/// Without a chain id, this is the legacy Ethereum convention
+/// (`27 + recovery_id`). With a chain id, this follows EIP-155
+/// (`recovery_id + 35 + chain_id * 2`), which binds the signature to a
+/// specific chain and prevents cross-chain replay.
+fn compute_v(recovery_id: u8, chain_id: Option<u64>) -> u64 {
+    match chain_id {
+        Some(chain_id) => recovery_id as u64 + 35 + chain_id * 2,
+        None => recovery_id as u64 + 27,
     }
 }
 
@@ -98,11 +132,20 @@ impl ProductionSigner {
 /// Args:
 ///     digest: Hex-encoded 32-byte message hash
 ///     key_hex: Optional hex-encoded private key (for testing)
+///     chain_id: Optional EIP-155 chain id; when supplied, `v` is computed
+///         as `recovery_id + 35 + chain_id * 2` instead of `27 + recovery_id`,
+///         binding the signature to that chain to prevent replay on others
 ///
 /// Returns:
-///     Dictionary with signature components (r, s, v, signature, latency_ms)
+///     Dictionary with signature components (r, s, v, recovery_id,
+///     signature, latency_ms)
 #[pyfunction]
-fn sign_digest(digest: &str, key_hex: Option<&str>) -> PyResult<pyo3::Py<pyo3::types::PyDict>> {
+#[pyo3(signature = (digest, key_hex = None, chain_id = None))]
+fn sign_digest(
+    digest: &str,
+    key_hex: Option<&str>,
+    chain_id: Option<u64>,
+) -> PyResult<pyo3::Py<pyo3::types::PyDict>> {
     // Parse digest from hex
     let digest_bytes = hex::decode(digest.trim_start_matches("0x"))
         .map_err(|e| PyValueError::new_err(format!("Invalid hex digest: {}", e)))?;
@@ -120,6 +163,7 @@ fn sign_digest(digest: &str, key_hex: Option<&str>) -> PyResult<pyo3::Py<pyo3::t
     let result = signer
         .sign(&digest_bytes)
         .map_err(|e| PyValueError::new_err(e))?;
+    let v = compute_v(result.recovery_id, chain_id);
 
     // Build Python dict
     Python::with_gil(|py| {
@@ -127,12 +171,84 @@ fn sign_digest(digest: &str, key_hex: Option<&str>) -> PyResult<pyo3::Py<pyo3::t
         dict.set_item("signature", format!("0x{}", hex::encode(&result.signature)))?;
         dict.set_item("r", format!("0x{}", hex::encode(&result.r)))?;
         dict.set_item("s", format!("0x{}", hex::encode(&result.s)))?;
-        dict.set_item("v", result.v)?;
+        dict.set_item("recovery_id", result.recovery_id)?;
+        dict.set_item("v", v)?;
         dict.set_item("latency_ms", result.latency_ms)?;
         Ok(dict.into())
     })
 }
 
+/// Signs many digests with a single signing key, amortizing the
+/// per-call signer setup that `sign_digest` pays every time.
+///
+/// Args:
+///     digests: Hex-encoded 32-byte message hashes to sign
+///     key_hex: Optional hex-encoded private key (random if omitted)
+///
+/// Returns:
+///     Dictionary with `signatures` (a list of per-digest dicts, same
+///     shape as `sign_digest`'s result), `total_latency_ms` for the
+///     whole batch, and `signatures_per_second` throughput.
+#[pyfunction]
+#[pyo3(signature = (digests, key_hex = None))]
+fn sign_batch(
+    py: Python<'_>,
+    digests: Vec<String>,
+    key_hex: Option<&str>,
+) -> PyResult<pyo3::Py<pyo3::types::PyDict>> {
+    use rayon::prelude::*;
+
+    let signer = if let Some(key) = key_hex {
+        let key_bytes = hex::decode(key.trim_start_matches("0x"))
+            .map_err(|e| PyValueError::new_err(format!("Invalid hex key: {}", e)))?;
+        ProductionSigner::from_bytes(&key_bytes).map_err(|e| PyValueError::new_err(e))?
+    } else {
+        ProductionSigner::new()
+    };
+
+    let digest_bytes = digests
+        .iter()
+        .map(|d| hex::decode(d.trim_start_matches("0x")))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex digest: {}", e)))?;
+
+    let start = Instant::now();
+
+    // Sign every digest in parallel across threads, releasing the GIL so
+    // other Python threads can run while the batch is in flight.
+    let results: Vec<Result<SignatureResult, String>> = py.allow_threads(|| {
+        digest_bytes
+            .par_iter()
+            .map(|digest| signer.sign(digest))
+            .collect()
+    });
+
+    let total_latency_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    let list = pyo3::types::PyList::empty(py);
+    for result in results {
+        let result = result.map_err(|e| PyValueError::new_err(e))?;
+        let entry = pyo3::types::PyDict::new(py);
+        entry.set_item("signature", format!("0x{}", hex::encode(&result.signature)))?;
+        entry.set_item("r", format!("0x{}", hex::encode(&result.r)))?;
+        entry.set_item("s", format!("0x{}", hex::encode(&result.s)))?;
+        entry.set_item("recovery_id", result.recovery_id)?;
+        entry.set_item("v", result.v)?;
+        entry.set_item("latency_ms", result.latency_ms)?;
+        list.append(entry)?;
+    }
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("signatures", list)?;
+    dict.set_item("total_latency_ms", total_latency_ms)?;
+    dict.set_item(
+        "signatures_per_second",
+        digests.len() as f64 / (total_latency_ms / 1000.0).max(f64::EPSILON),
+    )?;
+
+    Ok(dict.into())
+}
+
 /// Legacy function for backward compatibility
 /// Signs a transaction payload (computes keccak256 hash first)
 #[pyfunction]
@@ -152,14 +268,339 @@ fn sign_transaction(payload: &str) -> PyResult<String> {
 }
 
 /// Get public key from private key bytes
+///
+/// Args:
+///     key_hex: Hex-encoded private key
+///     compressed: If true, return the 33-byte compressed SEC1 encoding;
+///         defaults to the 65-byte uncompressed encoding
 #[pyfunction]
-fn get_public_key(key_hex: &str) -> PyResult<String> {
+#[pyo3(signature = (key_hex, compressed = false))]
+fn get_public_key(key_hex: &str, compressed: bool) -> PyResult<String> {
     let key_bytes = hex::decode(key_hex.trim_start_matches("0x"))
         .map_err(|e| PyValueError::new_err(format!("Invalid hex key: {}", e)))?;
 
     let signer = ProductionSigner::from_bytes(&key_bytes).map_err(|e| PyValueError::new_err(e))?;
 
-    Ok(format!("0x{}", hex::encode(signer.public_key())))
+    Ok(format!("0x{}", hex::encode(signer.public_key(compressed))))
+}
+
+/// Apply EIP-55 mixed-case checksumming to a 20-byte address.
+///
+/// Each hex digit of the address is uppercased if the corresponding nibble
+/// of `keccak256(lowercase_hex(address))` is >= 8.
+fn to_checksum_address(address_bytes: &[u8]) -> String {
+    use sha3::{Digest, Keccak256};
+
+    let lower_hex = hex::encode(address_bytes);
+    let hash = Keccak256::digest(lower_hex.as_bytes());
+
+    let checksummed: String = lower_hex
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if c.is_ascii_digit() {
+                c
+            } else {
+                // Each hash byte covers two hex chars (high then low nibble)
+                let nibble = if i % 2 == 0 {
+                    hash[i / 2] >> 4
+                } else {
+                    hash[i / 2] & 0x0f
+                };
+                if nibble >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            }
+        })
+        .collect();
+
+    format!("0x{}", checksummed)
+}
+
+/// Derive the raw 20-byte address bytes from an uncompressed public key.
+///
+/// This is the shared primitive behind `recover_address` and
+/// `public_key_to_address`; it does not apply any checksumming.
+fn address_bytes_from_verifying_key(verifying_key: &k256::ecdsa::VerifyingKey) -> Vec<u8> {
+    use sha3::{Digest, Keccak256};
+
+    // Uncompressed SEC1 point is 0x04 || X || Y; the address is derived
+    // from X || Y only, so the leading tag byte is stripped.
+    let encoded = verifying_key.to_encoded_point(false);
+    let untagged = &encoded.as_bytes()[1..];
+    let hash = Keccak256::digest(untagged);
+    hash[12..].to_vec()
+}
+
+/// Parses a hex-decoded public key into a `VerifyingKey`, accepting either
+/// tagged SEC1 encodings (33-byte compressed, 65-byte uncompressed
+/// `0x04 || X || Y`) or a bare 64-byte untagged `X || Y` — the form most
+/// Ethereum/secp256k1 tooling hands around. The untagged form is tagged
+/// with the `0x04` uncompressed prefix before parsing.
+fn verifying_key_from_pubkey_bytes(pubkey_bytes: &[u8]) -> PyResult<k256::ecdsa::VerifyingKey> {
+    use k256::ecdsa::VerifyingKey;
+
+    if pubkey_bytes.len() == 64 {
+        let mut tagged = Vec::with_capacity(65);
+        tagged.push(0x04);
+        tagged.extend_from_slice(pubkey_bytes);
+        VerifyingKey::from_sec1_bytes(&tagged)
+    } else {
+        VerifyingKey::from_sec1_bytes(pubkey_bytes)
+    }
+    .map_err(|e| PyValueError::new_err(format!("Invalid public key bytes: {}", e)))
+}
+
+/// Verifies an ECDSA signature over a prehashed 32-byte digest
+///
+/// Args:
+///     digest_hex: Hex-encoded 32-byte message hash
+///     signature_hex: Hex-encoded 64-byte signature (r || s)
+///     pubkey_hex: Hex-encoded SEC1 public key (compressed, uncompressed, or
+///         bare untagged 64-byte `X || Y`)
+///
+/// Returns:
+///     True if the signature is valid for the given digest and public key
+#[pyfunction]
+fn verify_digest(digest_hex: &str, signature_hex: &str, pubkey_hex: &str) -> PyResult<bool> {
+    use k256::ecdsa::{signature::hazmat::PrehashVerifier, Signature};
+
+    let digest = hex::decode(digest_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex digest: {}", e)))?;
+    let sig_bytes = hex::decode(signature_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex signature: {}", e)))?;
+    let pubkey_bytes = hex::decode(pubkey_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex public key: {}", e)))?;
+
+    let signature = Signature::from_slice(&sig_bytes)
+        .map_err(|e| PyValueError::new_err(format!("Invalid signature bytes: {}", e)))?;
+    let verifying_key = verifying_key_from_pubkey_bytes(&pubkey_bytes)?;
+
+    Ok(verifying_key.verify_prehash(&digest, &signature).is_ok())
+}
+
+/// Recovers the signer's public key from a signature and returns its address
+///
+/// Args:
+///     digest_hex: Hex-encoded 32-byte message hash that was signed
+///     r_hex: Hex-encoded r component of the signature
+///     s_hex: Hex-encoded s component of the signature
+///     v: Ethereum-style recovery value (27/28) or raw recovery id (0/1)
+///
+/// Returns:
+///     Hex-encoded 20-byte address derived from the recovered public key
+#[pyfunction]
+fn recover_address(digest_hex: &str, r_hex: &str, s_hex: &str, v: u8) -> PyResult<String> {
+    use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+
+    let digest = hex::decode(digest_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex digest: {}", e)))?;
+    let r = hex::decode(r_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex r: {}", e)))?;
+    let s = hex::decode(s_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex s: {}", e)))?;
+
+    if r.len() != 32 || s.len() != 32 {
+        return Err(PyValueError::new_err("r and s must each be 32 bytes"));
+    }
+
+    let mut sig_bytes = [0u8; 64];
+    sig_bytes[..32].copy_from_slice(&r);
+    sig_bytes[32..].copy_from_slice(&s);
+    let signature = Signature::from_slice(&sig_bytes)
+        .map_err(|e| PyValueError::new_err(format!("Invalid signature bytes: {}", e)))?;
+
+    let recid_byte = if v >= 27 { v - 27 } else { v };
+    let recid = RecoveryId::from_byte(recid_byte)
+        .ok_or_else(|| PyValueError::new_err(format!("Invalid recovery id: {}", v)))?;
+
+    let verifying_key = VerifyingKey::recover_from_prehash(&digest, &signature, recid)
+        .map_err(|e| PyValueError::new_err(format!("Recovery failed: {}", e)))?;
+
+    Ok(to_checksum_address(&address_bytes_from_verifying_key(
+        &verifying_key,
+    )))
+}
+
+/// Derives the EIP-55 checksummed Ethereum address from a public key
+///
+/// Args:
+///     pubkey_hex: Hex-encoded public key — tagged SEC1 (compressed or
+///         uncompressed) or a bare untagged 64-byte `X || Y`
+///
+/// Returns:
+///     EIP-55 checksummed `0x`-prefixed address
+#[pyfunction]
+fn public_key_to_address(pubkey_hex: &str) -> PyResult<String> {
+    let pubkey_bytes = hex::decode(pubkey_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex public key: {}", e)))?;
+    let verifying_key = verifying_key_from_pubkey_bytes(&pubkey_bytes)?;
+
+    Ok(to_checksum_address(&address_bytes_from_verifying_key(
+        &verifying_key,
+    )))
+}
+
+/// Convenience path to derive the EIP-55 checksummed address directly from
+/// a private key, without going through `get_public_key` first.
+///
+/// Args:
+///     key_hex: Hex-encoded private key
+///
+/// Returns:
+///     EIP-55 checksummed `0x`-prefixed address
+#[pyfunction]
+fn address_from_private_key(key_hex: &str) -> PyResult<String> {
+    let key_bytes = hex::decode(key_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex key: {}", e)))?;
+    let signer = ProductionSigner::from_bytes(&key_bytes).map_err(|e| PyValueError::new_err(e))?;
+
+    Ok(to_checksum_address(&address_bytes_from_verifying_key(
+        signer.key.verifying_key(),
+    )))
+}
+
+/// An `RngCore` that yields caller-supplied BIP-340 auxiliary randomness
+/// exactly once, then falls back to `OsRng`.
+///
+/// This lets `sign_schnorr` thread a caller-provided `aux_rand` through
+/// k256's `RandomizedSigner` API, which otherwise always draws its own
+/// randomness.
+struct FixedAuxRand {
+    aux: Option<[u8; 32]>,
+}
+
+impl k256::elliptic_curve::rand_core::RngCore for FixedAuxRand {
+    fn next_u32(&mut self) -> u32 {
+        use k256::elliptic_curve::rand_core::RngCore as _;
+        OsRng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        use k256::elliptic_curve::rand_core::RngCore as _;
+        OsRng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        use k256::elliptic_curve::rand_core::RngCore as _;
+        if let (32, Some(aux)) = (dest.len(), self.aux.take()) {
+            dest.copy_from_slice(&aux);
+        } else {
+            OsRng.fill_bytes(dest);
+        }
+    }
+
+    fn try_fill_bytes(
+        &mut self,
+        dest: &mut [u8],
+    ) -> Result<(), k256::elliptic_curve::rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl k256::elliptic_curve::rand_core::CryptoRng for FixedAuxRand {}
+
+/// Signs a 32-byte digest using BIP-340 Schnorr, as used by Bitcoin
+/// Taproot and other x-only-key Schnorr schemes.
+///
+/// Args:
+///     digest_hex: Hex-encoded 32-byte message to sign
+///     key_hex: Optional hex-encoded private key (random if omitted)
+///     aux_rand_hex: Optional hex-encoded 32-byte BIP-340 auxiliary
+///         randomness; `OsRng` is used when omitted
+///
+/// Returns:
+///     Dictionary with `signature` (64 bytes), `pubkey` (32-byte x-only
+///     public key), and `latency_ms`
+#[pyfunction]
+#[pyo3(signature = (digest_hex, key_hex = None, aux_rand_hex = None))]
+fn sign_schnorr(
+    digest_hex: &str,
+    key_hex: Option<&str>,
+    aux_rand_hex: Option<&str>,
+) -> PyResult<pyo3::Py<pyo3::types::PyDict>> {
+    use k256::schnorr::signature::RandomizedSigner;
+    use k256::schnorr::SigningKey;
+
+    let start = Instant::now();
+
+    let digest = hex::decode(digest_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex digest: {}", e)))?;
+    if digest.len() != 32 {
+        return Err(PyValueError::new_err(format!(
+            "Digest must be 32 bytes, got {}",
+            digest.len()
+        )));
+    }
+
+    let signing_key = match key_hex {
+        Some(key) => {
+            let key_bytes = hex::decode(key.trim_start_matches("0x"))
+                .map_err(|e| PyValueError::new_err(format!("Invalid hex key: {}", e)))?;
+            SigningKey::from_bytes(&key_bytes)
+                .map_err(|e| PyValueError::new_err(format!("Invalid key bytes: {}", e)))?
+        }
+        None => SigningKey::random(&mut OsRng),
+    };
+
+    let mut rng = match aux_rand_hex {
+        Some(aux_hex) => {
+            let aux_bytes = hex::decode(aux_hex.trim_start_matches("0x"))
+                .map_err(|e| PyValueError::new_err(format!("Invalid hex aux_rand: {}", e)))?;
+            let aux: [u8; 32] = aux_bytes
+                .try_into()
+                .map_err(|_| PyValueError::new_err("aux_rand must be 32 bytes"))?;
+            FixedAuxRand { aux: Some(aux) }
+        }
+        None => FixedAuxRand { aux: None },
+    };
+
+    let signature = signing_key.sign_with_rng(&mut rng, &digest);
+    let pubkey = signing_key.verifying_key().to_bytes();
+    let latency_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    Python::with_gil(|py| {
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item(
+            "signature",
+            format!("0x{}", hex::encode(signature.to_bytes())),
+        )?;
+        dict.set_item("pubkey", format!("0x{}", hex::encode(pubkey)))?;
+        dict.set_item("latency_ms", latency_ms)?;
+        Ok(dict.into())
+    })
+}
+
+/// Verifies a BIP-340 Schnorr signature over a 32-byte digest
+///
+/// Args:
+///     digest_hex: Hex-encoded 32-byte message that was signed
+///     signature_hex: Hex-encoded 64-byte BIP-340 signature
+///     pubkey_hex: Hex-encoded 32-byte x-only public key
+///
+/// Returns:
+///     True if the signature is valid for the given digest and public key
+#[pyfunction]
+fn verify_schnorr(digest_hex: &str, signature_hex: &str, pubkey_hex: &str) -> PyResult<bool> {
+    use k256::schnorr::signature::Verifier;
+    use k256::schnorr::{Signature, VerifyingKey};
+
+    let digest = hex::decode(digest_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex digest: {}", e)))?;
+    let sig_bytes = hex::decode(signature_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex signature: {}", e)))?;
+    let pubkey_bytes = hex::decode(pubkey_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex public key: {}", e)))?;
+
+    let signature = Signature::try_from(sig_bytes.as_slice())
+        .map_err(|e| PyValueError::new_err(format!("Invalid signature bytes: {}", e)))?;
+    let verifying_key = VerifyingKey::from_bytes(&pubkey_bytes)
+        .map_err(|e| PyValueError::new_err(format!("Invalid public key bytes: {}", e)))?;
+
+    Ok(verifying_key.verify(&digest, &signature).is_ok())
 }
 
 /// Python module with production signing functions
@@ -167,7 +608,23 @@ fn get_public_key(key_hex: &str) -> PyResult<String> {
 fn crypto_signer(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(sign_transaction, m)?)?;
     m.add_function(wrap_pyfunction!(sign_digest, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_batch, m)?)?;
     m.add_function(wrap_pyfunction!(get_public_key, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_digest, m)?)?;
+    m.add_function(wrap_pyfunction!(recover_address, m)?)?;
+    m.add_function(wrap_pyfunction!(public_key_to_address, m)?)?;
+    m.add_function(wrap_pyfunction!(address_from_private_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_schnorr, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_schnorr, m)?)?;
+    m.add_function(wrap_pyfunction!(split_key, m)?)?;
+    m.add_function(wrap_pyfunction!(round1_commit, m)?)?;
+    m.add_function(wrap_pyfunction!(round2_partial_sign, m)?)?;
+    m.add_function(wrap_pyfunction!(aggregate, m)?)?;
+    m.add_class::<KeyShare>()?;
+    m.add_class::<NonceShare>()?;
+    m.add_class::<NonceCommitment>()?;
+    m.add_class::<PartialSignature>()?;
+    m.add_class::<PartialTx>()?;
     Ok(())
 }
 
@@ -195,4 +652,215 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_recovery_id_round_trip() {
+        use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+
+        let signer = ProductionSigner::new();
+
+        for i in 0..32u8 {
+            // Deterministic but varied 32-byte digests
+            let mut digest = [0u8; 32];
+            digest[0] = i;
+            digest[31] = i.wrapping_mul(7);
+
+            let result = signer.sign(&digest).unwrap();
+
+            let signature = Signature::from_slice(&result.signature).unwrap();
+            let recid = RecoveryId::from_byte(result.recovery_id).unwrap();
+            let recovered =
+                VerifyingKey::recover_from_prehash(&digest, &signature, recid).unwrap();
+
+            assert_eq!(&recovered, signer.key.verifying_key());
+        }
+    }
+
+    #[test]
+    fn test_verify_digest_round_trip() {
+        let signer = ProductionSigner::new();
+        let digest = [7u8; 32];
+        let result = signer.sign(&digest).unwrap();
+
+        let digest_hex = format!("0x{}", hex::encode(digest));
+        let sig_hex = format!("0x{}", hex::encode(&result.signature));
+        let pubkey_hex = format!("0x{}", hex::encode(signer.public_key(false)));
+
+        assert!(verify_digest(&digest_hex, &sig_hex, &pubkey_hex).unwrap());
+
+        // A tampered digest must fail verification
+        let wrong_digest_hex = format!("0x{}", hex::encode([8u8; 32]));
+        assert!(!verify_digest(&wrong_digest_hex, &sig_hex, &pubkey_hex).unwrap());
+    }
+
+    #[test]
+    fn test_recover_address_matches_signer() {
+        let signer = ProductionSigner::new();
+        let digest = [9u8; 32];
+        let result = signer.sign(&digest).unwrap();
+
+        let digest_hex = format!("0x{}", hex::encode(digest));
+        let r_hex = format!("0x{}", hex::encode(&result.r));
+        let s_hex = format!("0x{}", hex::encode(&result.s));
+
+        let recovered = recover_address(&digest_hex, &r_hex, &s_hex, result.v).unwrap();
+        let expected =
+            to_checksum_address(&address_bytes_from_verifying_key(signer.key.verifying_key()));
+
+        assert_eq!(recovered, expected);
+    }
+
+    #[test]
+    fn test_compute_v_legacy_and_eip155() {
+        assert_eq!(compute_v(0, None), 27);
+        assert_eq!(compute_v(1, None), 28);
+
+        // Mainnet (chain id 1): v = recid + 35 + 2 = 37/38
+        assert_eq!(compute_v(0, Some(1)), 37);
+        assert_eq!(compute_v(1, Some(1)), 38);
+    }
+
+    #[test]
+    fn test_bip340_test_vector_0() {
+        // BIP-340 test vector index 0 (bip-340/test-vectors.csv)
+        let key_hex = "0000000000000000000000000000000000000000000000000000000000000003";
+        let key_hex = &key_hex[key_hex.len() - 64..];
+        let digest_hex = "0000000000000000000000000000000000000000000000000000000000000000";
+        let aux_rand_hex = "0000000000000000000000000000000000000000000000000000000000000000";
+
+        let expected_pubkey = "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+        let expected_signature = "e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca82\
+1525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0";
+
+        let result = Python::with_gil(|py| {
+            let dict = sign_schnorr(digest_hex, Some(key_hex), Some(aux_rand_hex)).unwrap();
+            let dict = dict.as_ref(py);
+            let signature: String = dict.get_item("signature").unwrap().extract().unwrap();
+            let pubkey: String = dict.get_item("pubkey").unwrap().extract().unwrap();
+            (signature, pubkey)
+        });
+
+        assert_eq!(result.0, format!("0x{}", expected_signature));
+        assert_eq!(result.1, format!("0x{}", expected_pubkey));
+
+        assert!(verify_schnorr(digest_hex, &result.0, &result.1).unwrap());
+    }
+
+    #[test]
+    fn test_bip340_test_vector_1() {
+        // BIP-340 test vector index 1 (bip-340/test-vectors.csv), exercising
+        // a non-zero aux_rand, unlike vector 0's degenerate all-zero case.
+        let key_hex = "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF";
+        let digest_hex = "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89";
+        let aux_rand_hex = "0000000000000000000000000000000000000000000000000000000000000001";
+
+        let expected_pubkey = "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659";
+        let expected_signature = "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE334\
+18906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A";
+
+        let result = Python::with_gil(|py| {
+            let dict = sign_schnorr(digest_hex, Some(key_hex), Some(aux_rand_hex)).unwrap();
+            let dict = dict.as_ref(py);
+            let signature: String = dict.get_item("signature").unwrap().extract().unwrap();
+            let pubkey: String = dict.get_item("pubkey").unwrap().extract().unwrap();
+            (signature, pubkey)
+        });
+
+        assert_eq!(
+            result.0.to_ascii_lowercase(),
+            format!("0x{}", expected_signature.to_ascii_lowercase())
+        );
+        assert_eq!(
+            result.1.to_ascii_lowercase(),
+            format!("0x{}", expected_pubkey.to_ascii_lowercase())
+        );
+
+        assert!(verify_schnorr(digest_hex, &result.0, &result.1).unwrap());
+    }
+
+    #[test]
+    fn test_bip340_verification_failure_cases() {
+        // A tampered signature, tampered pubkey, or tampered message must
+        // all fail verification against an otherwise-valid BIP-340 vector.
+        let digest_hex = "0000000000000000000000000000000000000000000000000000000000000000";
+        let pubkey_hex =
+            "0xf9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+        let signature_hex = "0xe907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca82\
+1525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0";
+
+        assert!(verify_schnorr(digest_hex, signature_hex, pubkey_hex).unwrap());
+
+        let mut tampered_sig = hex::decode(&signature_hex[2..]).unwrap();
+        *tampered_sig.last_mut().unwrap() ^= 0x01;
+        let tampered_sig_hex = format!("0x{}", hex::encode(tampered_sig));
+        assert!(!verify_schnorr(digest_hex, &tampered_sig_hex, pubkey_hex).unwrap());
+
+        // A different, but still validly-encoded, x-only public key (from
+        // BIP-340 test vector 1) must not verify against this signature.
+        let other_pubkey_hex =
+            "0xdff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+        assert!(!verify_schnorr(digest_hex, signature_hex, other_pubkey_hex).unwrap());
+
+        let wrong_digest_hex =
+            "0x0000000000000000000000000000000000000000000000000000000000000001";
+        assert!(!verify_schnorr(wrong_digest_hex, signature_hex, pubkey_hex).unwrap());
+    }
+
+    #[test]
+    fn test_eip55_checksum_known_vector() {
+        // From EIP-55 test vectors
+        let address = hex::decode("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap();
+        assert_eq!(
+            to_checksum_address(&address),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+    }
+
+    #[test]
+    fn test_public_key_to_address_matches_get_public_key() {
+        let key_hex = "0000000000000000000000000000000000000000000000000000000000000001";
+        let key_hex = &key_hex[key_hex.len() - 64..];
+        let pubkey_hex = get_public_key(key_hex, false).unwrap();
+
+        let from_pubkey = public_key_to_address(&pubkey_hex).unwrap();
+        let from_private_key = address_from_private_key(key_hex).unwrap();
+
+        assert_eq!(from_pubkey, from_private_key);
+    }
+
+    #[test]
+    fn test_sign_batch_signatures_verify_against_key() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let key_hex = hex::encode(signing_key.to_bytes());
+
+        let digests: Vec<String> = (0..8u8)
+            .map(|i| format!("0x{}", hex::encode([i; 32])))
+            .collect();
+
+        Python::with_gil(|py| {
+            let result = sign_batch(py, digests.clone(), Some(&key_hex)).unwrap();
+            let result = result.as_ref(py);
+
+            let signatures = result.get_item("signatures").unwrap();
+            let signatures = signatures.downcast::<pyo3::types::PyList>().unwrap();
+            assert_eq!(signatures.len(), digests.len());
+
+            let total_latency_ms: f64 = result
+                .get_item("total_latency_ms")
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert!(total_latency_ms >= 0.0);
+
+            let pubkey_hex = format!(
+                "0x{}",
+                hex::encode(signing_key.verifying_key().to_encoded_point(false).as_bytes())
+            );
+            for (i, digest_hex) in digests.iter().enumerate() {
+                let entry = signatures.get_item(i).unwrap();
+                let sig_hex: String = entry.get_item("signature").unwrap().extract().unwrap();
+                assert!(verify_digest(digest_hex, &sig_hex, &pubkey_hex).unwrap());
+            }
+        });
+    }
 }
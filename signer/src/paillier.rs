@@ -0,0 +1,196 @@
+//! Minimal textbook Paillier cryptosystem.
+//!
+//! [`crate::threshold`] uses this as the additively homomorphic
+//! encryption scheme behind its multiplicative-to-additive (MtA) share
+//! conversion, which is what lets its two-party threshold ECDSA protocol
+//! combine nonce and key shares without ever reconstructing the full
+//! nonce or the full private key.
+//!
+//! This is deliberately minimal, not a hardened general-purpose Paillier
+//! implementation: key generation uses modest-sized primes so signing
+//! stays fast enough for tests, and there are no zero-knowledge range
+//! proofs guarding against a counterparty submitting out-of-range
+//! ciphertexts. That's sufficient for a semi-honest threshold signer; a
+//! production deployment would want larger keys and the accompanying
+//! proofs from GG18/GG20.
+
+use k256::elliptic_curve::rand_core::OsRng;
+use num_bigint::{BigInt, BigUint, RandBigInt, Sign, ToBigInt};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+/// Bit length of each of the two primes `p`, `q`; the Paillier modulus
+/// `n = p*q` is double this. Kept modest so key generation and signing
+/// stay fast in tests; see the module doc.
+const PRIME_BITS: u64 = 512;
+
+#[derive(Clone)]
+pub struct PaillierPublicKey {
+    pub n: BigUint,
+    pub n_squared: BigUint,
+}
+
+#[derive(Clone)]
+pub struct PaillierKeypair {
+    pub public: PaillierPublicKey,
+    lambda: BigUint,
+    mu: BigUint,
+}
+
+fn is_probably_prime(candidate: &BigUint, rounds: u32) -> bool {
+    let small_primes = [2u32, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+    if small_primes
+        .iter()
+        .any(|p| candidate == &BigUint::from(*p))
+    {
+        return true;
+    }
+    if small_primes
+        .iter()
+        .any(|p| (candidate % BigUint::from(*p)).is_zero())
+    {
+        return false;
+    }
+
+    let one = BigUint::one();
+    let two = &one + &one;
+    let n_minus_one = candidate - &one;
+
+    let mut d = n_minus_one.clone();
+    let mut r = 0u32;
+    while (&d % &two).is_zero() {
+        d /= &two;
+        r += 1;
+    }
+
+    'witness: for _ in 0..rounds {
+        let a = OsRng.gen_biguint_range(&two, &n_minus_one);
+        let mut x = a.modpow(&d, candidate);
+        if x == one || x == n_minus_one {
+            continue;
+        }
+        for _ in 0..r.saturating_sub(1) {
+            x = x.modpow(&two, candidate);
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+fn random_prime(bits: u64) -> BigUint {
+    loop {
+        let mut candidate = OsRng.gen_biguint(bits);
+        candidate.set_bit(bits - 1, true);
+        candidate.set_bit(0, true);
+        if is_probably_prime(&candidate, 40) {
+            return candidate;
+        }
+    }
+}
+
+/// Modular inverse of `value` mod `modulus`, via the extended Euclidean
+/// algorithm (`BigUint` has no built-in modular inverse).
+fn mod_inverse(value: &BigUint, modulus: &BigUint) -> BigUint {
+    let value_signed = value.to_bigint().expect("BigUint always converts");
+    let modulus_signed = modulus.to_bigint().expect("BigUint always converts");
+    let egcd = value_signed.extended_gcd(&modulus_signed);
+    let mut inverse = egcd.x % &modulus_signed;
+    if inverse.sign() == Sign::Minus {
+        inverse += &modulus_signed;
+    }
+    inverse
+        .to_biguint()
+        .expect("reduced mod a positive modulus is non-negative")
+}
+
+fn random_unit(modulus: &BigUint) -> BigUint {
+    loop {
+        let candidate = OsRng.gen_biguint_range(&BigUint::one(), modulus);
+        if candidate.gcd(modulus) == BigUint::one() {
+            return candidate;
+        }
+    }
+}
+
+/// Generates a fresh Paillier keypair.
+pub fn generate_keypair() -> PaillierKeypair {
+    let p = random_prime(PRIME_BITS);
+    let q = random_prime(PRIME_BITS);
+    let n = &p * &q;
+    let n_squared = &n * &n;
+
+    // With g = n + 1 (the standard simplification), lambda = lcm(p-1, q-1)
+    // and mu = lambda^{-1} mod n.
+    let p_minus_one = &p - BigUint::one();
+    let q_minus_one = &q - BigUint::one();
+    let lambda = (&p_minus_one * &q_minus_one) / p_minus_one.gcd(&q_minus_one);
+    let mu = mod_inverse(&lambda, &n);
+
+    PaillierKeypair {
+        public: PaillierPublicKey { n, n_squared },
+        lambda,
+        mu,
+    }
+}
+
+/// Encrypts `plaintext` (taken mod `pk.n`) under `pk`.
+pub fn encrypt(pk: &PaillierPublicKey, plaintext: &BigUint) -> BigUint {
+    let m = plaintext % &pk.n;
+    // g = n + 1, so g^m mod n^2 = 1 + m*n mod n^2 (standard shortcut).
+    let g_to_m = (BigUint::one() + &m * &pk.n) % &pk.n_squared;
+    let r = random_unit(&pk.n);
+    let r_to_n = r.modpow(&pk.n, &pk.n_squared);
+    (g_to_m * r_to_n) % &pk.n_squared
+}
+
+/// Decrypts `ciphertext` with `keypair`, returning the plaintext mod `n`.
+pub fn decrypt(keypair: &PaillierKeypair, ciphertext: &BigUint) -> BigUint {
+    let n = &keypair.public.n;
+    let n_squared = &keypair.public.n_squared;
+    let u = ciphertext.modpow(&keypair.lambda, n_squared);
+    let l = (&u - BigUint::one()) / n;
+    (l * &keypair.mu) % n
+}
+
+/// Homomorphic addition: `Dec(add(c1, c2)) = Dec(c1) + Dec(c2) mod n`.
+pub fn add(pk: &PaillierPublicKey, c1: &BigUint, c2: &BigUint) -> BigUint {
+    (c1 * c2) % &pk.n_squared
+}
+
+/// Homomorphic scalar multiplication: `Dec(scalar_mul(c, k)) = k * Dec(c) mod n`.
+pub fn scalar_mul(pk: &PaillierPublicKey, c: &BigUint, scalar: &BigUint) -> BigUint {
+    c.modpow(scalar, &pk.n_squared)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt_round_trip() {
+        let keypair = generate_keypair();
+        let plaintext = BigUint::from(424242u64);
+        let ciphertext = encrypt(&keypair.public, &plaintext);
+        assert_eq!(decrypt(&keypair, &ciphertext), plaintext);
+    }
+
+    #[test]
+    fn test_homomorphic_add_and_scalar_mul() {
+        let keypair = generate_keypair();
+        let a = BigUint::from(17u64);
+        let b = BigUint::from(29u64);
+        let scalar = BigUint::from(5u64);
+
+        let c_a = encrypt(&keypair.public, &a);
+        let c_b = encrypt(&keypair.public, &b);
+
+        let summed = add(&keypair.public, &c_a, &c_b);
+        assert_eq!(decrypt(&keypair, &summed), &a + &b);
+
+        let scaled = scalar_mul(&keypair.public, &c_a, &scalar);
+        assert_eq!(decrypt(&keypair, &scaled), (&a * &scalar) % &keypair.public.n);
+    }
+}
@@ -0,0 +1,163 @@
+//! PSBT-style deferred input signing.
+//!
+//! Models signing a multi-input transaction the way Partially Signed
+//! Bitcoin Transactions do: inputs are declared up front with their
+//! sighash digests, signatures are collected incrementally — possibly
+//! from different key holders, over time — instead of the all-or-nothing
+//! single-digest flow in [`crate::sign_digest`], and `finalize` assembles
+//! whatever has been collected so far.
+
+use k256::ecdsa::{signature::hazmat::PrehashSigner, RecoveryId, Signature, SigningKey};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// One input of a [`PartialTx`]: the sighash digest that needs signing,
+/// an optional hint about which key is expected to sign it, and the
+/// signature once collected.
+///
+/// This only models an ECDSA (legacy/segwit) spending path; a taproot
+/// input branch would add a schnorr signature variant alongside this one.
+struct PartialInput {
+    digest: Vec<u8>,
+    key_id: Option<String>,
+    signature: Option<(Vec<u8>, u8)>,
+}
+
+/// A transaction whose inputs are signed incrementally, one at a time,
+/// potentially by different key holders, instead of all at once.
+#[pyclass]
+pub struct PartialTx {
+    inputs: Vec<PartialInput>,
+}
+
+#[pymethods]
+impl PartialTx {
+    #[new]
+    fn new() -> Self {
+        PartialTx { inputs: Vec::new() }
+    }
+
+    /// Declares a new input with its sighash digest and, optionally, the
+    /// identifier of the key expected to sign it.
+    ///
+    /// Returns:
+    ///     The new input's index, to be passed to `sign_input`.
+    #[pyo3(signature = (digest_hex, key_id = None))]
+    fn add_input(&mut self, digest_hex: &str, key_id: Option<String>) -> PyResult<usize> {
+        let digest = hex::decode(digest_hex.trim_start_matches("0x"))
+            .map_err(|e| PyValueError::new_err(format!("Invalid hex digest: {}", e)))?;
+        if digest.len() != 32 {
+            return Err(PyValueError::new_err(format!(
+                "Digest must be 32 bytes, got {}",
+                digest.len()
+            )));
+        }
+
+        self.inputs.push(PartialInput {
+            digest,
+            key_id,
+            signature: None,
+        });
+        Ok(self.inputs.len() - 1)
+    }
+
+    /// Signs the input at `index` with `key_hex` over that input's
+    /// sighash digest. Different inputs may be signed with different
+    /// keys, in any order, as each key holder becomes available.
+    fn sign_input(&mut self, index: usize, key_hex: &str) -> PyResult<()> {
+        let input = self
+            .inputs
+            .get_mut(index)
+            .ok_or_else(|| PyValueError::new_err(format!("No such input: {}", index)))?;
+
+        let key_bytes = hex::decode(key_hex.trim_start_matches("0x"))
+            .map_err(|e| PyValueError::new_err(format!("Invalid hex key: {}", e)))?;
+        let signing_key = SigningKey::from_bytes(key_bytes.as_slice().into())
+            .map_err(|e| PyValueError::new_err(format!("Invalid key bytes: {}", e)))?;
+
+        let (signature, recid): (Signature, RecoveryId) = signing_key
+            .sign_prehash_recoverable(&input.digest)
+            .map_err(|e| PyValueError::new_err(format!("Signing failed: {}", e)))?;
+
+        input.signature = Some((signature.to_bytes().to_vec(), recid.to_byte()));
+        Ok(())
+    }
+
+    /// True once every declared input has a signature attached.
+    fn is_complete(&self) -> bool {
+        self.inputs.iter().all(|input| input.signature.is_some())
+    }
+
+    /// Assembles the signatures collected so far, keyed by input index.
+    ///
+    /// Inputs without a signature yet are omitted rather than raising an
+    /// error, so an orchestrator can call this to inspect signing
+    /// progress without needing every input signed first.
+    fn finalize(&self) -> PyResult<Py<PyDict>> {
+        Python::with_gil(|py| {
+            let dict = PyDict::new(py);
+            for (index, input) in self.inputs.iter().enumerate() {
+                if let Some((sig_bytes, recid)) = &input.signature {
+                    let entry = PyDict::new(py);
+                    entry.set_item("signature", format!("0x{}", hex::encode(sig_bytes)))?;
+                    entry.set_item("r", format!("0x{}", hex::encode(&sig_bytes[..32])))?;
+                    entry.set_item("s", format!("0x{}", hex::encode(&sig_bytes[32..])))?;
+                    entry.set_item("recovery_id", *recid)?;
+                    entry.set_item("v", 27u16 + *recid as u16)?;
+                    if let Some(key_id) = &input.key_id {
+                        entry.set_item("key_id", key_id)?;
+                    }
+                    dict.set_item(index, entry)?;
+                }
+            }
+            Ok(dict.into())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_incremental_signing_in_any_order() {
+        let mut tx = PartialTx::new();
+        let digest_a = format!("0x{}", hex::encode([1u8; 32]));
+        let digest_b = format!("0x{}", hex::encode([2u8; 32]));
+        let idx_a = tx.add_input(&digest_a, Some("key-a".to_string())).unwrap();
+        let idx_b = tx.add_input(&digest_b, Some("key-b".to_string())).unwrap();
+
+        let key_a = hex::encode(SigningKey::random(&mut k256::elliptic_curve::rand_core::OsRng).to_bytes());
+        let key_b = hex::encode(SigningKey::random(&mut k256::elliptic_curve::rand_core::OsRng).to_bytes());
+
+        assert!(!tx.is_complete());
+
+        // Sign out of order, as if key holders became available at
+        // different times.
+        tx.sign_input(idx_b, &key_b).unwrap();
+        assert!(!tx.is_complete());
+        tx.sign_input(idx_a, &key_a).unwrap();
+        assert!(tx.is_complete());
+
+        Python::with_gil(|py| {
+            let finalized = tx.finalize().unwrap();
+            let finalized = finalized.as_ref(py);
+            assert!(finalized.get_item(idx_a).unwrap().is_some());
+            assert!(finalized.get_item(idx_b).unwrap().is_some());
+        });
+    }
+
+    #[test]
+    fn test_finalize_before_all_inputs_signed() {
+        let mut tx = PartialTx::new();
+        let digest = format!("0x{}", hex::encode([3u8; 32]));
+        tx.add_input(&digest, None).unwrap();
+
+        Python::with_gil(|py| {
+            let finalized = tx.finalize().unwrap();
+            let finalized = finalized.as_ref(py);
+            assert_eq!(finalized.len(), 0);
+        });
+    }
+}
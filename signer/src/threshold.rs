@@ -0,0 +1,617 @@
+//! 2-party (2-of-2) threshold ECDSA via a Paillier-based
+//! multiplicative-to-additive (MtA) share conversion, in the style of
+//! Lindell's two-party ECDSA protocol (and the two-party case of
+//! GG18/GG20). The aggregated signature is a standard secp256k1 ECDSA
+//! `(r, s, v)` triple, so it works unchanged with the same
+//! [`crate::verify_digest`] and [`crate::recover_address`] used
+//! everywhere else in this crate.
+//!
+//! ECDSA's `s = k⁻¹(m + r·d)` mixes the nonce `k` and the key `d`
+//! multiplicatively through `k⁻¹`, so — unlike Schnorr's purely additive
+//! `s = k + c·d` — there's no way for two parties to each hold an
+//! additive share of `k` and `d` and locally combine partial `s` values
+//! without ever bringing the full `k` or `d` together in one place. A
+//! real threshold ECDSA therefore needs an interactive
+//! multiplicative-to-additive step; this module implements that with
+//! Paillier's additively homomorphic encryption ([`crate::paillier`]):
+//!
+//! - [`split_key`] splits the private key multiplicatively, `d = x1 *
+//!   x2 mod n`, between party 1 (who also generates a Paillier keypair)
+//!   and party 2, so neither share alone determines `d`.
+//! - [`round1_commit`]: party 1 samples a secret nonce `k1`, commits to
+//!   (and, once every commitment is collected, reveals) `R1 = k1·G`,
+//!   and publishes Paillier encryptions of `k1⁻¹` and `k1⁻¹·x1` —
+//!   revealing these is safe, since they are ciphertexts under party
+//!   1's own key that only party 1 can decrypt. Party 2 just samples
+//!   `k2` and commits to/reveals `R2 = k2·G`.
+//! - [`round2_partial_sign`] (run by party 2): computes the combined
+//!   nonce point `R = k2·R1` and `r = R.x`, then — using only Paillier's
+//!   homomorphic addition and scalar multiplication, never decryption —
+//!   combines party 1's ciphertexts into an encryption of `s` blinded
+//!   by a random multiple of the curve order.
+//! - [`aggregate`] (run by party 1): decrypts that combination with its
+//!   Paillier secret key and reduces mod the curve order to recover
+//!   `s`. This is the only point where the two parties' contributions
+//!   to `s` come together, and it never requires forming `k = k1*k2`
+//!   or `d = x1*x2`.
+//!
+//! This is the semi-honest-secure core of the protocol: it omits the
+//! zero-knowledge range proofs a real GG18/GG20 deployment uses to stop
+//! a malicious party 2 from submitting out-of-range MtA exponents, or a
+//! malicious party 1 from misusing a dishonestly generated Paillier
+//! key. It is also fixed at 2-of-2; a general t-of-n threshold ECDSA
+//! needs pairwise MtA between every pair of signers and is out of scope
+//! here.
+
+use k256::ecdsa::{RecoveryId, Signature, SigningKey};
+use k256::elliptic_curve::{
+    ops::Reduce, point::AffineCoordinates, rand_core::OsRng, sec1::ToEncodedPoint, Field,
+    PrimeField,
+};
+use k256::{AffinePoint, EncodedPoint, ProjectivePoint, Scalar, U256};
+use num_bigint::BigUint;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use sha3::{Digest, Keccak256};
+use zeroize::Zeroizing;
+
+use crate::paillier::{self, PaillierKeypair, PaillierPublicKey};
+
+/// secp256k1 group order, as a `BigUint`, for reducing Paillier
+/// plaintexts (which live mod the much larger Paillier modulus `n`)
+/// back down to a curve scalar.
+fn curve_order() -> BigUint {
+    BigUint::parse_bytes(
+        b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+        16,
+    )
+    .expect("valid constant")
+}
+
+fn scalar_to_bytes(scalar: &Scalar) -> [u8; 32] {
+    scalar.to_bytes().into()
+}
+
+fn scalar_from_hex(hex_str: &str) -> PyResult<Scalar> {
+    let bytes = hex::decode(hex_str.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex scalar: {}", e)))?;
+    if bytes.len() != 32 {
+        return Err(PyValueError::new_err("scalar must be 32 bytes"));
+    }
+    let repr: Option<Scalar> = Scalar::from_repr(bytes.as_slice().into()).into();
+    repr.ok_or_else(|| PyValueError::new_err("scalar out of range"))
+}
+
+fn point_from_hex(point_hex: &str) -> PyResult<AffinePoint> {
+    let bytes = hex::decode(point_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid point encoding: {}", e)))?;
+    let encoded = EncodedPoint::from_bytes(&bytes)
+        .map_err(|e| PyValueError::new_err(format!("Invalid point encoding: {}", e)))?;
+    let affine: Option<AffinePoint> = AffinePoint::from_encoded_point(&encoded).into();
+    affine.ok_or_else(|| PyValueError::new_err("Invalid point encoding"))
+}
+
+fn scalar_to_biguint(scalar: &Scalar) -> BigUint {
+    BigUint::from_bytes_be(&scalar_to_bytes(scalar))
+}
+
+/// Reduces a `BigUint` mod the curve order and converts it to a `Scalar`.
+fn biguint_to_scalar_mod_q(value: &BigUint) -> PyResult<Scalar> {
+    let reduced = value % curve_order();
+    let mut bytes = reduced.to_bytes_be();
+    if bytes.len() > 32 {
+        return Err(PyValueError::new_err("scalar overflow after reduction"));
+    }
+    let mut padded = vec![0u8; 32 - bytes.len()];
+    padded.append(&mut bytes);
+    let arr: [u8; 32] = padded
+        .try_into()
+        .map_err(|_| PyValueError::new_err("bad scalar length"))?;
+    let repr: Option<Scalar> = Scalar::from_repr(arr.into()).into();
+    repr.ok_or_else(|| PyValueError::new_err("scalar out of range"))
+}
+
+fn ciphertext_to_hex(value: &BigUint) -> String {
+    format!("0x{}", value.to_str_radix(16))
+}
+
+fn ciphertext_from_hex(hex_str: &str) -> PyResult<BigUint> {
+    BigUint::parse_bytes(hex_str.trim_start_matches("0x").as_bytes(), 16)
+        .ok_or_else(|| PyValueError::new_err("Invalid hex ciphertext"))
+}
+
+fn hash_commitment(point_bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(Keccak256::digest(point_bytes)))
+}
+
+/// One party's share of a secp256k1 private key, produced by
+/// [`split_key`]'s multiplicative split `d = x1 * x2 mod n`. `index` is
+/// `1` (the Paillier keypair holder) or `2` (the combiner). The raw
+/// scalar is kept in a `Zeroizing` buffer and wiped on drop; the private
+/// key itself is never reconstructed by any signing round.
+#[pyclass]
+#[derive(Clone)]
+pub struct KeyShare {
+    #[pyo3(get)]
+    pub index: u16,
+    /// Compressed SEC1 encoding of the joint public key `Q = d·G`.
+    #[pyo3(get)]
+    pub pubkey_hex: String,
+    share: Zeroizing<[u8; 32]>,
+    paillier_public: PaillierPublicKey,
+    /// `Some` only for `index == 1`; party 2 never holds the Paillier
+    /// secret key, which is what keeps it from decrypting party 1's MtA
+    /// ciphertexts into `k1` or `x1`.
+    paillier_keypair: Option<PaillierKeypair>,
+}
+
+impl KeyShare {
+    fn scalar(&self) -> PyResult<Scalar> {
+        let repr: Option<Scalar> = Scalar::from_repr((*self.share).into()).into();
+        repr.ok_or_else(|| PyValueError::new_err("corrupt key share"))
+    }
+}
+
+/// A party's secret nonce contribution for one signing session. Produced
+/// in round 1 and consumed in round 2; the secret scalar is zeroized on
+/// drop. Only `point_hex` (the nonce point, safe to broadcast) and, for
+/// party 1, the two Paillier ciphertexts are ever exposed to Python.
+#[pyclass]
+#[derive(Clone)]
+pub struct NonceShare {
+    #[pyo3(get)]
+    pub index: u16,
+    /// `R_i = k_i·G`, compressed SEC1 encoding.
+    #[pyo3(get)]
+    pub point_hex: String,
+    /// Party 1 only: Paillier encryption of `k1⁻¹ mod q`.
+    #[pyo3(get)]
+    pub mta_ciphertext_a_hex: Option<String>,
+    /// Party 1 only: Paillier encryption of `k1⁻¹ · x1 mod q`.
+    #[pyo3(get)]
+    pub mta_ciphertext_b_hex: Option<String>,
+    k: Zeroizing<[u8; 32]>,
+}
+
+impl NonceShare {
+    fn scalar(&self) -> PyResult<Scalar> {
+        let repr: Option<Scalar> = Scalar::from_repr((*self.k).into()).into();
+        repr.ok_or_else(|| PyValueError::new_err("corrupt nonce share"))
+    }
+}
+
+/// A party's round-1 hash commitment to their nonce point, published
+/// before anyone reveals their actual `R_i`, so neither party can bias
+/// the combined nonce by choosing theirs last.
+#[pyclass]
+#[derive(Clone)]
+pub struct NonceCommitment {
+    #[pyo3(get)]
+    pub index: u16,
+    #[pyo3(get)]
+    pub commitment_hex: String,
+}
+
+/// Party 2's contribution to the signature: the combined nonce's `r`,
+/// plus a Paillier ciphertext that decrypts (only by party 1, who holds
+/// the matching secret key) to `s` blinded by a random multiple of the
+/// curve order.
+#[pyclass]
+#[derive(Clone)]
+pub struct PartialSignature {
+    #[pyo3(get)]
+    pub r_hex: String,
+    #[pyo3(get)]
+    pub masked_ciphertext_hex: String,
+}
+
+/// Splits a secp256k1 private key into two multiplicative shares, `x1`
+/// and `x2`, such that `d = x1 * x2 mod n`. This (like the rest of a
+/// trusted-dealer threshold scheme) is the one place the full private
+/// key is ever assembled; no signing round reconstructs it.
+///
+/// Returns the two parties' shares: `(party1, party2)`. Party 1's share
+/// carries a freshly generated Paillier keypair used for MtA during
+/// signing; party 2's share carries only party 1's Paillier public key.
+#[pyfunction]
+pub fn split_key(key_hex: &str) -> PyResult<(KeyShare, KeyShare)> {
+    let key_bytes = hex::decode(key_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex key: {}", e)))?;
+    let signing_key = SigningKey::from_bytes(key_bytes.as_slice().into())
+        .map_err(|e| PyValueError::new_err(format!("Invalid key bytes: {}", e)))?;
+    let d: Scalar = *signing_key.as_nonzero_scalar().as_ref();
+
+    let mut x1 = Scalar::random(&mut OsRng);
+    while bool::from(x1.is_zero()) {
+        x1 = Scalar::random(&mut OsRng);
+    }
+    let x1_inv: Option<Scalar> = x1.invert().into();
+    let x1_inv = x1_inv.ok_or_else(|| PyValueError::new_err("failed to invert key share"))?;
+    let x2 = d * x1_inv;
+
+    let pubkey_point = (ProjectivePoint::GENERATOR * d).to_affine();
+    let pubkey_hex = format!(
+        "0x{}",
+        hex::encode(pubkey_point.to_encoded_point(true).as_bytes())
+    );
+
+    let paillier_keypair = paillier::generate_keypair();
+    let paillier_public = paillier_keypair.public.clone();
+
+    let share1 = KeyShare {
+        index: 1,
+        pubkey_hex: pubkey_hex.clone(),
+        share: Zeroizing::new(scalar_to_bytes(&x1)),
+        paillier_public: paillier_public.clone(),
+        paillier_keypair: Some(paillier_keypair),
+    };
+    let share2 = KeyShare {
+        index: 2,
+        pubkey_hex,
+        share: Zeroizing::new(scalar_to_bytes(&x2)),
+        paillier_public,
+        paillier_keypair: None,
+    };
+
+    Ok((share1, share2))
+}
+
+/// Round 1: generate this party's secret nonce `k_i` and a hash
+/// commitment to its public point `R_i = k_i·G`. The actual point (kept
+/// on the returned [`NonceShare`]) should only be broadcast to the other
+/// party once both commitments have been exchanged. For party 1, this
+/// also computes the MtA ciphertexts consumed by [`round2_partial_sign`].
+#[pyfunction]
+pub fn round1_commit(share: &KeyShare) -> PyResult<(NonceShare, NonceCommitment)> {
+    let mut k = Scalar::random(&mut OsRng);
+    while bool::from(k.is_zero()) {
+        k = Scalar::random(&mut OsRng);
+    }
+    let point = (ProjectivePoint::GENERATOR * k).to_affine();
+    let point_bytes = point.to_encoded_point(true);
+    let point_hex = format!("0x{}", hex::encode(point_bytes.as_bytes()));
+    let commitment_hex = hash_commitment(point_bytes.as_bytes());
+
+    let (mta_ciphertext_a_hex, mta_ciphertext_b_hex) = if share.index == 1 {
+        let keypair = share
+            .paillier_keypair
+            .as_ref()
+            .ok_or_else(|| PyValueError::new_err("party 1's share is missing its Paillier key"))?;
+        let k_inv: Option<Scalar> = k.invert().into();
+        let k_inv = k_inv.ok_or_else(|| PyValueError::new_err("failed to invert nonce"))?;
+        let x1 = share.scalar()?;
+
+        let ciphertext_a = paillier::encrypt(&keypair.public, &scalar_to_biguint(&k_inv));
+        let ciphertext_b = paillier::encrypt(&keypair.public, &scalar_to_biguint(&(k_inv * x1)));
+        (
+            Some(ciphertext_to_hex(&ciphertext_a)),
+            Some(ciphertext_to_hex(&ciphertext_b)),
+        )
+    } else {
+        (None, None)
+    };
+
+    Ok((
+        NonceShare {
+            index: share.index,
+            point_hex,
+            mta_ciphertext_a_hex,
+            mta_ciphertext_b_hex,
+            k: Zeroizing::new(scalar_to_bytes(&k)),
+        },
+        NonceCommitment {
+            index: share.index,
+            commitment_hex,
+        },
+    ))
+}
+
+fn verify_revealed_points(
+    commitments: &[NonceCommitment],
+    revealed_points: &[(u16, String)],
+) -> PyResult<()> {
+    for commitment in commitments {
+        let (_, point_hex) = revealed_points
+            .iter()
+            .find(|(index, _)| *index == commitment.index)
+            .ok_or_else(|| {
+                PyValueError::new_err("missing nonce reveal for a committed participant")
+            })?;
+        let bytes = hex::decode(point_hex.trim_start_matches("0x"))
+            .map_err(|e| PyValueError::new_err(format!("Invalid point encoding: {}", e)))?;
+        if hash_commitment(&bytes) != commitment.commitment_hex {
+            return Err(PyValueError::new_err(
+                "revealed nonce point does not match this participant's round-1 commitment",
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn find_point<'a>(revealed_points: &'a [(u16, String)], index: u16) -> PyResult<&'a str> {
+    revealed_points
+        .iter()
+        .find(|(i, _)| *i == index)
+        .map(|(_, point_hex)| point_hex.as_str())
+        .ok_or_else(|| PyValueError::new_err(format!("missing revealed point for party {}", index)))
+}
+
+/// Round 2, run by party 2 (the combiner). Computes the combined nonce
+/// point `R = k2·R1` and `r = R.x`, then uses Paillier's homomorphic
+/// addition and scalar multiplication — never decryption — to combine
+/// party 1's MtA ciphertexts (`nonce_share1`'s `mta_ciphertext_{a,b}_hex`,
+/// as revealed alongside its nonce point) into an encryption of `s`,
+/// blinded by a random multiple of the curve order so party 1 learns
+/// nothing beyond `s mod q` when it later decrypts.
+#[pyfunction]
+pub fn round2_partial_sign(
+    share2: &KeyShare,
+    nonce_share2: &NonceShare,
+    nonce_share1: &NonceShare,
+    digest_hex: &str,
+    commitments: Vec<NonceCommitment>,
+    revealed_points: Vec<(u16, String)>,
+) -> PyResult<PartialSignature> {
+    if share2.index != 2 {
+        return Err(PyValueError::new_err(
+            "round2_partial_sign must be called by the combining party (index 2)",
+        ));
+    }
+    if nonce_share1.index != 1 {
+        return Err(PyValueError::new_err(
+            "nonce_share1 must be party 1's nonce share",
+        ));
+    }
+    verify_revealed_points(&commitments, &revealed_points)?;
+
+    let digest = hex::decode(digest_hex.trim_start_matches("0x"))
+        .map_err(|e| PyValueError::new_err(format!("Invalid hex digest: {}", e)))?;
+    if digest.len() != 32 {
+        return Err(PyValueError::new_err(format!(
+            "Digest must be 32 bytes, got {}",
+            digest.len()
+        )));
+    }
+
+    let r1_point = point_from_hex(find_point(&revealed_points, 1)?)?;
+    let k2 = nonce_share2.scalar()?;
+    let r_point = (ProjectivePoint::from(r1_point) * k2).to_affine();
+    let r_scalar = <Scalar as Reduce<U256>>::reduce_bytes(&r_point.x());
+
+    let m_scalar =
+        <Scalar as Reduce<U256>>::reduce_bytes(k256::FieldBytes::from_slice(&digest));
+    let k2_inv: Option<Scalar> = k2.invert().into();
+    let k2_inv = k2_inv.ok_or_else(|| PyValueError::new_err("failed to invert nonce"))?;
+    let x2 = share2.scalar()?;
+
+    // Exponents applied (in the clear, by party 2) to party 1's
+    // ciphertexts: e1 masks k1^-1 by m*k2^-1, e2 masks k1^-1*x1 by
+    // r*x2*k2^-1, so that c_a^e1 * c_b^e2 decrypts to
+    // k2^-1*k1^-1*m + k2^-1*r*x2*k1^-1*x1 = k^-1*(m + r*d) = s.
+    let exponent_a = m_scalar * k2_inv;
+    let exponent_b = r_scalar * x2 * k2_inv;
+
+    let ciphertext_a = ciphertext_from_hex(nonce_share1.mta_ciphertext_a_hex.as_deref().ok_or_else(
+        || PyValueError::new_err("party 1's nonce share is missing its MtA ciphertext"),
+    )?)?;
+    let ciphertext_b = ciphertext_from_hex(nonce_share1.mta_ciphertext_b_hex.as_deref().ok_or_else(
+        || PyValueError::new_err("party 1's nonce share is missing its MtA ciphertext"),
+    )?)?;
+
+    let term_a = paillier::scalar_mul(&share2.paillier_public, &ciphertext_a, &scalar_to_biguint(&exponent_a));
+    let term_b = paillier::scalar_mul(&share2.paillier_public, &ciphertext_b, &scalar_to_biguint(&exponent_b));
+    let combined = paillier::add(&share2.paillier_public, &term_a, &term_b);
+
+    // Blind with an encryption of a random multiple of the curve order:
+    // this leaves the plaintext's value mod q unchanged (what we want
+    // party 1 to recover) while masking the raw combination so party 1
+    // never sees the unblinded intermediate value.
+    let mut rho_bytes = [0u8; 32];
+    k256::elliptic_curve::rand_core::RngCore::fill_bytes(&mut OsRng, &mut rho_bytes);
+    let rho = BigUint::from_bytes_be(&rho_bytes);
+    let blinding = paillier::encrypt(&share2.paillier_public, &(rho * curve_order()));
+    let masked = paillier::add(&share2.paillier_public, &combined, &blinding);
+
+    Ok(PartialSignature {
+        r_hex: format!("0x{}", hex::encode(scalar_to_bytes(&r_scalar))),
+        masked_ciphertext_hex: ciphertext_to_hex(&masked),
+    })
+}
+
+/// Round 3, run by party 1: decrypts [`round2_partial_sign`]'s masked
+/// ciphertext with its Paillier secret key, reduces mod the curve order
+/// to recover `s`, normalizes it to low-s form, and assembles a
+/// standard secp256k1 ECDSA signature (`r`, `s`, recovery id, `v`) —
+/// usable directly with [`crate::verify_digest`] and
+/// [`crate::recover_address`].
+#[pyfunction]
+pub fn aggregate(
+    share1: &KeyShare,
+    nonce_share1: &NonceShare,
+    revealed_points: Vec<(u16, String)>,
+    partial: PartialSignature,
+) -> PyResult<Py<PyDict>> {
+    if share1.index != 1 {
+        return Err(PyValueError::new_err(
+            "aggregate must be called by the Paillier keypair holder (index 1)",
+        ));
+    }
+    let keypair = share1
+        .paillier_keypair
+        .as_ref()
+        .ok_or_else(|| PyValueError::new_err("party 1's share is missing its Paillier key"))?;
+
+    let masked = ciphertext_from_hex(&partial.masked_ciphertext_hex)?;
+    let s_plain = paillier::decrypt(keypair, &masked);
+    let mut s_scalar = biguint_to_scalar_mod_q(&s_plain)?;
+
+    // Independently recompute R = k1 * R2 to cross-check party 2's
+    // claimed r and to derive the recovery id's y-parity bit, instead of
+    // trusting party 2's r_hex outright.
+    let r2_point = point_from_hex(find_point(&revealed_points, 2)?)?;
+    let k1 = nonce_share1.scalar()?;
+    let r_point = (ProjectivePoint::from(r2_point) * k1).to_affine();
+    let r_scalar = <Scalar as Reduce<U256>>::reduce_bytes(&r_point.x());
+    if r_scalar != scalar_from_hex(&partial.r_hex)? {
+        return Err(PyValueError::new_err(
+            "party 2's claimed r does not match the independently recomputed combined nonce",
+        ));
+    }
+
+    let mut recovery_id = u8::from(bool::from(r_point.y_is_odd()));
+    // secp256k1's order is prime and odd, so s and its negation -s are
+    // never equal; negating a high s gives the canonical low-s form and, to
+    // match, the recovery id's low bit must flip with it (s and q - s
+    // correspond to the two nonce points R and -R).
+    let high_s = scalar_to_biguint(&s_scalar) > curve_order() / BigUint::from(2u32);
+    if high_s {
+        s_scalar = -s_scalar;
+        recovery_id ^= 1;
+    }
+
+    let signature = Signature::from_scalars(scalar_to_bytes(&r_scalar), scalar_to_bytes(&s_scalar))
+        .map_err(|e| PyValueError::new_err(format!("Invalid signature scalars: {}", e)))?;
+    RecoveryId::from_byte(recovery_id)
+        .ok_or_else(|| PyValueError::new_err("invalid recovery id"))?;
+    let sig_bytes = signature.to_bytes();
+
+    Python::with_gil(|py| {
+        let dict = PyDict::new(py);
+        dict.set_item("signature", format!("0x{}", hex::encode(sig_bytes)))?;
+        dict.set_item("r", format!("0x{}", hex::encode(&sig_bytes[..32])))?;
+        dict.set_item("s", format!("0x{}", hex::encode(&sig_bytes[32..])))?;
+        dict.set_item("recovery_id", recovery_id)?;
+        dict.set_item("v", 27u16 + recovery_id as u16)?;
+        dict.set_item("pubkey", share1.pubkey_hex.clone())?;
+        Ok(dict.into())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{recover_address, verify_digest};
+
+    fn run_session(digest_hex: &str) -> (KeyShare, pyo3::Py<PyDict>, SigningKey) {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let key_hex = hex::encode(signing_key.to_bytes());
+
+        let (share1, share2) = split_key(&key_hex).unwrap();
+
+        let (nonce_share1, commitment1) = round1_commit(&share1).unwrap();
+        let (nonce_share2, commitment2) = round1_commit(&share2).unwrap();
+        let commitments = vec![commitment1, commitment2];
+        let revealed_points = vec![
+            (1u16, nonce_share1.point_hex.clone()),
+            (2u16, nonce_share2.point_hex.clone()),
+        ];
+
+        let partial = round2_partial_sign(
+            &share2,
+            &nonce_share2,
+            &nonce_share1,
+            digest_hex,
+            commitments,
+            revealed_points.clone(),
+        )
+        .unwrap();
+
+        let dict = aggregate(&share1, &nonce_share1, revealed_points, partial).unwrap();
+        (share1, dict, signing_key)
+    }
+
+    #[test]
+    fn test_threshold_ecdsa_round_trip() {
+        let digest_hex = format!("0x{}", hex::encode([42u8; 32]));
+        let (_, dict, signing_key) = run_session(&digest_hex);
+
+        let (sig_hex, v) = Python::with_gil(|py| {
+            let dict = dict.as_ref(py);
+            let sig_hex: String = dict.get_item("signature").unwrap().extract().unwrap();
+            let v: u8 = dict.get_item("v").unwrap().extract().unwrap();
+            (sig_hex, v)
+        });
+
+        let pubkey_hex = format!(
+            "0x{}",
+            hex::encode(
+                signing_key
+                    .verifying_key()
+                    .to_encoded_point(false)
+                    .as_bytes()
+            )
+        );
+
+        // The aggregated signature must be a standard ECDSA signature,
+        // verifiable and recoverable with the same functions used
+        // everywhere else in this crate.
+        assert!(verify_digest(&digest_hex, &sig_hex, &pubkey_hex).unwrap());
+
+        let r_hex = format!("0x{}", &sig_hex[2..66]);
+        let s_hex = format!("0x{}", &sig_hex[66..130]);
+        let recovered = recover_address(&digest_hex, &r_hex, &s_hex, v).unwrap();
+        let expected_address =
+            crate::public_key_to_address(&pubkey_hex).unwrap();
+        assert_eq!(recovered, expected_address);
+    }
+
+    #[test]
+    fn test_split_key_produces_consistent_public_key() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let key_hex = hex::encode(signing_key.to_bytes());
+        let (share1, share2) = split_key(&key_hex).unwrap();
+
+        assert_eq!(share1.pubkey_hex, share2.pubkey_hex);
+        assert_eq!(
+            share1.pubkey_hex,
+            format!(
+                "0x{}",
+                hex::encode(signing_key.verifying_key().to_encoded_point(true).as_bytes())
+            )
+        );
+    }
+
+    /// Demonstrates that the masked value party 2 sends is only readable
+    /// by the legitimate Paillier secret key holder (party 1): decrypting
+    /// it with a different, freshly generated keypair does not recover
+    /// the real `s` (with overwhelming probability), confirming party 2
+    /// cannot learn `s`, `k1`, or `x1` from what it computes and sends.
+    #[test]
+    fn test_masked_ciphertext_unreadable_without_party1_paillier_key() {
+        let digest_hex = format!("0x{}", hex::encode([7u8; 32]));
+        let signing_key = SigningKey::random(&mut OsRng);
+        let key_hex = hex::encode(signing_key.to_bytes());
+        let (share1, share2) = split_key(&key_hex).unwrap();
+
+        let (nonce_share1, commitment1) = round1_commit(&share1).unwrap();
+        let (nonce_share2, commitment2) = round1_commit(&share2).unwrap();
+        let commitments = vec![commitment1, commitment2];
+        let revealed_points = vec![
+            (1u16, nonce_share1.point_hex.clone()),
+            (2u16, nonce_share2.point_hex.clone()),
+        ];
+
+        let partial = round2_partial_sign(
+            &share2,
+            &nonce_share2,
+            &nonce_share1,
+            &digest_hex,
+            commitments,
+            revealed_points.clone(),
+        )
+        .unwrap();
+
+        let real_s = {
+            let keypair = share1.paillier_keypair.as_ref().unwrap();
+            let masked = ciphertext_from_hex(&partial.masked_ciphertext_hex).unwrap();
+            paillier::decrypt(keypair, &masked)
+        };
+
+        let attacker_keypair = paillier::generate_keypair();
+        let masked = ciphertext_from_hex(&partial.masked_ciphertext_hex).unwrap();
+        let garbage = paillier::decrypt(&attacker_keypair, &masked);
+
+        assert_ne!(garbage, real_s);
+    }
+}